@@ -2,24 +2,38 @@ open Base
 open Stdio
 open Dsl_parser
 
-let usage_msg = "dsl-parser [--input <file>] [--output <format>] [--context <string>]"
+let usage_msg = "dsl-parser [--input <file>] [--output <format>] [--context <string>] [sql/cypher options]"
 let input_file = ref ""
 let output_format = ref "json"
 let context_hint = ref ""
+let sql_dialect = ref "generic"
+let table_prefix = ref ""
+let inserts_only = ref false
+let label_prefix = ref ""
+let cypher_merge = ref false
 
 let set_input filename = input_file := filename
 let set_output format = output_format := format  
 let set_context ctx = context_hint := ctx
 
+let show_version () =
+  printf "dsl-parser v%s\n" (Version.get ());
+  Stdlib.exit 0
+
 let speclist =
   [("--input", Stdlib.Arg.String set_input, " Input .colo file");
    ("-i", Stdlib.Arg.String set_input, " Input .colo file (short)");
-   ("--output", Stdlib.Arg.String set_output, " Output format (json|prompt|lua|sql|cypher)");
+   ("--output", Stdlib.Arg.String set_output, " Output format (json|prompt|lua|sql|cypher|yaml|markdown)");
    ("-o", Stdlib.Arg.String set_output, " Output format (short)");
    ("--context", Stdlib.Arg.String set_context, " Context hint for prompt generation");
    ("-c", Stdlib.Arg.String set_context, " Context hint (short)");
-   ("--version", Stdlib.Arg.Unit (fun () -> printf "dsl-parser v0.2.0\n"; Stdlib.exit 0), " Show version");
-   ("-v", Stdlib.Arg.Unit (fun () -> printf "dsl-parser v0.2.0\n"; Stdlib.exit 0), " Show version (short)")]
+   ("--sql-dialect", Stdlib.Arg.Set_string sql_dialect, " SQL dialect (generic|postgres|sqlite|mysql)");
+   ("--table-prefix", Stdlib.Arg.Set_string table_prefix, " Prefix for SQL table names");
+   ("--inserts-only", Stdlib.Arg.Set inserts_only, " Emit SQL inserts without CREATE TABLE statements");
+   ("--label-prefix", Stdlib.Arg.Set_string label_prefix, " Prefix for Cypher node labels");
+   ("--cypher-merge", Stdlib.Arg.Set cypher_merge, " Emit Cypher MERGE instead of CREATE");
+   ("--version", Stdlib.Arg.Unit show_version, " Show version");
+   ("-v", Stdlib.Arg.Unit show_version, " Show version (short)")]
 
 let string_to_target = function
   | "json" -> Some Compiler.Json
@@ -27,6 +41,8 @@ let string_to_target = function
   | "lua" -> Some Compiler.Lua
   | "sql" -> Some Compiler.Sql
   | "cypher" -> Some Compiler.Cypher
+  | "yaml" -> Some Compiler.Yaml
+  | "markdown" -> Some Compiler.Markdown
   | _ -> None
 
 let read_file_or_stdin filename =
@@ -35,7 +51,7 @@ let read_file_or_stdin filename =
   else
     In_channel.read_all filename
 
-let compile_and_output dsl_content target _context =
+let compile_and_output dsl_content target _context ~sql ~cypher =
   match Ast.parse_personality_from_string ~filename:(!input_file) dsl_content with
   | Error errors ->
       eprintf "Parse errors:\n";
@@ -45,7 +61,7 @@ let compile_and_output dsl_content target _context =
       Stdlib.exit 1
   | Ok personality ->
       let context_opt = if String.is_empty !context_hint then None else Some !context_hint in
-      match Compiler.compile personality target ?context:context_opt () with
+      match Compiler.compile personality target ?context:context_opt ~sql ~cypher () with
       | Error errors ->
           eprintf "Compilation errors:\n";
           List.iter errors ~f:(fun e -> 
@@ -64,9 +80,19 @@ let () =
     | Some t -> t
     | None -> 
         eprintf "Invalid output format: %s\n" !output_format;
-        eprintf "Valid formats: json, prompt, lua, sql, cypher\n";
+        eprintf "Valid formats: json, prompt, lua, sql, cypher, yaml, markdown\n";
+        Stdlib.exit 1
+  in
+  
+  let sql = match Compiler.sql_dialect_of_string !sql_dialect with
+    | Some dialect ->
+        Compiler.{ dialect; table_prefix = !table_prefix; inserts_only = !inserts_only }
+    | None ->
+        eprintf "Invalid SQL dialect: %s\n" !sql_dialect;
+        eprintf "Valid dialects: generic, postgres, sqlite, mysql\n";
         Stdlib.exit 1
   in
+  let cypher = Compiler.{ label_prefix = !label_prefix; use_merge = !cypher_merge } in
   
   (* Read input *)
   let dsl_content = 
@@ -83,4 +109,4 @@ let () =
   );
   
   (* Compile and output *)
-  compile_and_output dsl_content target !context_hint
\ No newline at end of file
+  compile_and_output dsl_content target !context_hint ~sql ~cypher
\ No newline at end of file
@@ -9,6 +9,40 @@ type target =
   | Prompt
   | Sql
   | Cypher
+  | Yaml
+  | Markdown
+
+(** SQL dialects for the Sql target *)
+type sql_dialect =
+  | Generic   (** Portable DDL with plain inserts *)
+  | Postgres  (** ON CONFLICT upserts, DOUBLE PRECISION floats *)
+  | Sqlite    (** INSERT OR REPLACE, TEXT/REAL column types *)
+  | Mysql     (** ON DUPLICATE KEY UPDATE, backslash-escaped strings *)
+
+(** Options for the Sql target *)
+type sql_options = {
+  dialect: sql_dialect;
+  table_prefix: string;  (** Prepended to every table name *)
+  inserts_only: bool;    (** Omit CREATE TABLE statements *)
+}
+
+(** Options for the Cypher target *)
+type cypher_options = {
+  label_prefix: string;  (** Prepended to every node label *)
+  use_merge: bool;       (** Emit MERGE instead of CREATE *)
+}
+
+let default_sql_options = { dialect = Generic; table_prefix = ""; inserts_only = false }
+
+let default_cypher_options = { label_prefix = ""; use_merge = false }
+
+let sql_dialect_of_string s =
+  match String.lowercase s with
+  | "generic" -> Some Generic
+  | "postgres" | "postgresql" -> Some Postgres
+  | "sqlite" -> Some Sqlite
+  | "mysql" -> Some Mysql
+  | _ -> None
 
 (** Compilation errors *)
 type compiler_error = 
@@ -91,6 +125,10 @@ let context_to_string = function
   | Types.Time_of_day ctx -> ctx
   | Types.Emotional_state ctx -> ctx
 
+(** Collapse line breaks so a value cannot escape a single-line context *)
+let single_line s =
+  String.map s ~f:(function '\n' | '\r' -> ' ' | c -> c)
+
 let trait_modifier_to_json modifier = 
   match modifier with
   | Types.Decay (rate, unit) -> 
@@ -155,6 +193,39 @@ return personality|}
       in
       Ok lua
 
+(** Human-readable renderings shared by the Prompt and Markdown targets *)
+let trait_modifier_to_string = function
+  | Types.When ctx -> Printf.sprintf "enhanced when %s" (context_to_string ctx)
+  | Types.Unless ctx -> Printf.sprintf "suppressed unless %s" (context_to_string ctx)
+  | Types.Amplifies (trait, factor) -> Printf.sprintf "amplifies %s by %.1fx" trait factor
+  | Types.Decay (rate, unit) -> Printf.sprintf "naturally decays %.2f per %s" rate (time_unit_to_string unit)
+  | Types.Transforms_to (trait, _factor, _) -> Printf.sprintf "can evolve into %s" trait
+
+let behavior_condition_to_string = function
+  | Types.Trait_above (trait, threshold) -> Printf.sprintf "when your %s > %.1f" trait threshold
+  | Types.Context_match ctx -> Printf.sprintf "when in %s context" ctx
+  | Types.Tired -> "when experiencing fatigue"
+  | Types.Motivated -> "when highly motivated"
+  | Types.Time_range (start, end_) -> Printf.sprintf "during %s to %s" start end_
+
+let behavior_action_to_string = function
+  | Types.Prefer value -> Printf.sprintf "prefer %s" value
+  | Types.Seek value -> Printf.sprintf "actively seek %s" value
+  | Types.Avoid value -> Printf.sprintf "avoid %s" value
+  | Types.Set_style (style, value) -> Printf.sprintf "use %s style: %s" style value
+
+let evolution_trigger_to_string = function
+  | Types.Learns topic -> Printf.sprintf "learning about %s" topic
+  | Types.Interaction_count count -> Printf.sprintf "after %d interactions" count
+  | Types.Feedback_score score -> Printf.sprintf "receiving %.1f feedback score" score
+  | Types.Time_in_domain (domain, unit, time) -> Printf.sprintf "spending %d %s in %s" time (time_unit_to_string unit) domain
+
+let evolution_effect_to_string = function
+  | Types.Trait_adjust (trait, delta) -> Printf.sprintf "your %s will %s by %.2f" trait (if Float.(delta > 0.0) then "increase" else "decrease") (Float.abs delta)
+  | Types.Unlock_domain domain -> Printf.sprintf "you'll gain access to %s knowledge" domain
+  | Types.Add_connection (from_d, to_d, _strength) -> Printf.sprintf "you'll develop stronger connections between %s and %s" from_d to_d
+  | Types.New_behavior _behavior -> "you'll develop new behavioral patterns"
+
 (** Enhanced AI Language Model Prompt compilation *)  
 let compile_to_prompt personality context_hint = 
   match validate_personality personality with
@@ -172,12 +243,7 @@ let compile_to_prompt personality context_hint =
         let modifier_desc = match t.modifiers with
           | [] -> ""
           | mods -> 
-            let mod_strs = List.map mods ~f:(function
-              | Types.When ctx -> Printf.sprintf "enhanced when %s" (context_to_string ctx)
-              | Types.Unless ctx -> Printf.sprintf "suppressed unless %s" (context_to_string ctx)
-              | Types.Amplifies (trait, factor) -> Printf.sprintf "amplifies %s by %.1fx" trait factor
-              | Types.Decay (rate, unit) -> Printf.sprintf "naturally decays %.2f per %s" rate (time_unit_to_string unit)
-              | Types.Transforms_to (trait, _factor, _) -> Printf.sprintf "can evolve into %s" trait) in
+            let mod_strs = List.map mods ~f:trait_modifier_to_string in
             " (" ^ String.concat mod_strs ~sep:"; " ^ ")" in
         base_desc ^ modifier_desc) in
         
@@ -201,17 +267,8 @@ let compile_to_prompt personality context_hint =
         | [] -> ""
         | behaviors ->
           let behavior_strs = List.map behaviors ~f:(fun behavior ->
-            let condition_str = match behavior.condition with
-              | Types.Trait_above (trait, threshold) -> Printf.sprintf "when your %s > %.1f" trait threshold
-              | Types.Context_match ctx -> Printf.sprintf "when in %s context" ctx
-              | Types.Tired -> "when experiencing fatigue"
-              | Types.Motivated -> "when highly motivated"
-              | Types.Time_range (start, end_) -> Printf.sprintf "during %s to %s" start end_ in
-            let action_str = match behavior.action with
-              | Types.Prefer value -> Printf.sprintf "prefer %s" value
-              | Types.Seek value -> Printf.sprintf "actively seek %s" value
-              | Types.Avoid value -> Printf.sprintf "avoid %s" value
-              | Types.Set_style (style, value) -> Printf.sprintf "use %s style: %s" style value in
+            let condition_str = behavior_condition_to_string behavior.condition in
+            let action_str = behavior_action_to_string behavior.action in
             Printf.sprintf "- %s, %s" (String.capitalize condition_str) action_str) in
           Printf.sprintf "\n\nBehavioral Guidelines:\n%s" (String.concat behavior_strs ~sep:"\n") in
           
@@ -220,16 +277,8 @@ let compile_to_prompt personality context_hint =
         | [] -> ""
         | evolutions ->
           let evolution_strs = List.map evolutions ~f:(fun evolution ->
-            let trigger_str = match evolution.trigger with
-              | Types.Learns topic -> Printf.sprintf "learning about %s" topic
-              | Types.Interaction_count count -> Printf.sprintf "after %d interactions" count
-              | Types.Feedback_score score -> Printf.sprintf "receiving %.1f feedback score" score
-              | Types.Time_in_domain (domain, unit, time) -> Printf.sprintf "spending %d %s in %s" time (time_unit_to_string unit) domain in
-            let effect_str = match evolution.action with
-              | Types.Trait_adjust (trait, delta) -> Printf.sprintf "your %s will %s by %.2f" trait (if Float.(delta > 0.0) then "increase" else "decrease") (Float.abs delta)
-              | Types.Unlock_domain domain -> Printf.sprintf "you'll gain access to %s knowledge" domain
-              | Types.Add_connection (from_d, to_d, _strength) -> Printf.sprintf "you'll develop stronger connections between %s and %s" from_d to_d
-              | Types.New_behavior _behavior -> "you'll develop new behavioral patterns" in
+            let trigger_str = evolution_trigger_to_string evolution.trigger in
+            let effect_str = evolution_effect_to_string evolution.action in
             Printf.sprintf "- Through %s, %s" trigger_str effect_str) in
           Printf.sprintf "\n\nPersonality Evolution:\nYou are aware that your personality can evolve through interactions:\n%s" (String.concat evolution_strs ~sep:"\n") in
 
@@ -260,124 +309,179 @@ let compile_to_prompt personality context_hint =
       Ok prompt
 
 (** SQL compilation *)
-let compile_to_sql personality =
+let sql_quote options s =
+  let escaped = String.concat_map s ~f:(function
+    | '\'' -> "''"
+    | '\\' when (match options.dialect with Mysql -> true | _ -> false) -> "\\\\"
+    | c -> String.of_char c) in
+  Printf.sprintf "'%s'" escaped
+
+let sql_string_type options = match options.dialect with
+  | Sqlite -> "TEXT"
+  | Generic | Postgres | Mysql -> "VARCHAR(255)"
+
+let sql_short_string_type options = match options.dialect with
+  | Sqlite -> "TEXT"
+  | Generic | Postgres | Mysql -> "VARCHAR(50)"
+
+let sql_float_type options = match options.dialect with
+  | Generic -> "FLOAT"
+  | Postgres -> "DOUBLE PRECISION"
+  | Sqlite -> "REAL"
+  | Mysql -> "DOUBLE"
+
+let sql_timestamp_type options = match options.dialect with
+  | Sqlite -> "TEXT"
+  | Generic | Postgres | Mysql -> "TIMESTAMP"
+
+let sql_create_table options ~table ~columns ~constraints =
+  let foreign_key = match table with
+    | "personalities" -> []
+    | _ -> [Printf.sprintf "FOREIGN KEY (personality_name) REFERENCES %spersonalities(name)" options.table_prefix] in
+  Printf.sprintf "CREATE TABLE IF NOT EXISTS %s%s (\n  %s\n);"
+    options.table_prefix table (String.concat ~sep:",\n  " (columns @ constraints @ foreign_key))
+
+let sql_insert options ~table ~columns ~key rows =
+  match rows with
+  | [] -> Printf.sprintf "-- No rows for %s%s" options.table_prefix table
+  | rows ->
+      let updates = List.filter columns ~f:(fun c -> not (List.mem key c ~equal:String.equal)) in
+      let verb = match options.dialect with
+        | Sqlite -> "INSERT OR REPLACE INTO"
+        | Generic | Postgres | Mysql -> "INSERT INTO" in
+      let upsert = match options.dialect, updates with
+        | (Generic | Sqlite), _ -> ""
+        | Postgres, [] ->
+            Printf.sprintf "\nON CONFLICT (%s) DO NOTHING" (String.concat ~sep:", " key)
+        | Postgres, updates ->
+            Printf.sprintf "\nON CONFLICT (%s) DO UPDATE SET %s" (String.concat ~sep:", " key)
+              (String.concat ~sep:", " (List.map updates ~f:(fun c -> Printf.sprintf "%s = EXCLUDED.%s" c c)))
+        | Mysql, [] ->
+            Printf.sprintf "\nON DUPLICATE KEY UPDATE %s = %s" (List.hd_exn key) (List.hd_exn key)
+        | Mysql, updates ->
+            "\nON DUPLICATE KEY UPDATE " ^
+            String.concat ~sep:", " (List.map updates ~f:(fun c -> Printf.sprintf "%s = VALUES(%s)" c c)) in
+      Printf.sprintf "%s %s%s (%s) VALUES\n  %s%s;"
+        verb options.table_prefix table (String.concat ~sep:", " columns)
+        (String.concat ~sep:",\n  " rows) upsert
+
+let compile_to_sql ?(options = default_sql_options) personality =
   match validate_personality personality with
   | Error errors -> Error errors
   | Ok p ->
-      let traits_sql = String.concat ~sep:",\n  " (List.mapi p.traits ~f:(fun i t ->
-        Printf.sprintf "('%s', %d, '%s', %f, '%s')" 
-          p.name i t.name t.strength (String.concat ~sep:";" (List.map t.modifiers ~f:show_trait_modifier)))) in
-      
-      let knowledge_sql = String.concat ~sep:",\n  " (List.fold p.knowledge ~init:[] ~f:(fun acc domain ->
-        let domain_topics = List.mapi domain.topics ~f:(fun i (topic, level) ->
-          Printf.sprintf "('%s', '%s', %d, '%s', '%s')" 
-            p.name domain.name i topic (knowledge_level_to_string level)) in
-        acc @ domain_topics)) in
+      let q = sql_quote options in
+      let str = sql_string_type options in
+      let name_sql = q p.name in
+
+      let traits_sql = List.mapi p.traits ~f:(fun i t ->
+        Printf.sprintf "(%s, %d, %s, %f, %s)" 
+          name_sql i (q t.name) t.strength (q (String.concat ~sep:";" (List.map t.modifiers ~f:show_trait_modifier)))) in
       
-      let behaviors_sql = String.concat ~sep:",\n  " (List.mapi p.behaviors ~f:(fun i behavior ->
-        Printf.sprintf "('%s', %d, '%s', '%s')" 
-          p.name i (show_behavior_condition behavior.condition) (show_behavior_action behavior.action))) in
+      let knowledge_sql = List.concat_map p.knowledge ~f:(fun domain ->
+        List.mapi domain.topics ~f:(fun i (topic, level) ->
+          Printf.sprintf "(%s, %s, %d, %s, %s)" 
+            name_sql (q domain.name) i (q topic) (q (knowledge_level_to_string level)))) in
       
-      let evolution_sql = String.concat ~sep:",\n  " (List.mapi p.evolution ~f:(fun i evolution ->
-        Printf.sprintf "('%s', %d, '%s', '%s')" 
-          p.name i (show_evolution_trigger evolution.trigger) (show_evolution_effect evolution.action))) in
+      let behaviors_sql = List.mapi p.behaviors ~f:(fun i behavior ->
+        Printf.sprintf "(%s, %d, %s, %s)" 
+          name_sql i (q (show_behavior_condition behavior.condition)) (q (show_behavior_action behavior.action))) in
       
-      let sql = Printf.sprintf {|-- Generated SQL for Personality: %s
-
--- Create tables if they don't exist
-CREATE TABLE IF NOT EXISTS personalities (
-  name VARCHAR(255) PRIMARY KEY,
-  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
-);
-
-CREATE TABLE IF NOT EXISTS traits (
-  personality_name VARCHAR(255),
-  trait_order INTEGER,
-  trait_name VARCHAR(255),
-  strength FLOAT,
-  modifiers TEXT,
-  PRIMARY KEY (personality_name, trait_name),
-  FOREIGN KEY (personality_name) REFERENCES personalities(name)
-);
-
-CREATE TABLE IF NOT EXISTS knowledge_topics (
-  personality_name VARCHAR(255),
-  domain_name VARCHAR(255),
-  topic_order INTEGER,
-  topic_name VARCHAR(255),
-  level VARCHAR(50),
-  PRIMARY KEY (personality_name, domain_name, topic_name),
-  FOREIGN KEY (personality_name) REFERENCES personalities(name)
-);
-
-CREATE TABLE IF NOT EXISTS behaviors (
-  personality_name VARCHAR(255),
-  behavior_order INTEGER,
-  condition_type TEXT,
-  action_type TEXT,
-  PRIMARY KEY (personality_name, behavior_order),
-  FOREIGN KEY (personality_name) REFERENCES personalities(name)
-);
-
-CREATE TABLE IF NOT EXISTS evolution_rules (
-  personality_name VARCHAR(255),
-  rule_order INTEGER,
-  trigger_type TEXT,
-  effect_type TEXT,
-  PRIMARY KEY (personality_name, rule_order),
-  FOREIGN KEY (personality_name) REFERENCES personalities(name)
-);
-
--- Insert data
-INSERT INTO personalities (name) VALUES ('%s');
-
-INSERT INTO traits (personality_name, trait_order, trait_name, strength, modifiers) VALUES
-  %s;
-
-INSERT INTO knowledge_topics (personality_name, domain_name, topic_order, topic_name, level) VALUES
-  %s;
-
-INSERT INTO behaviors (personality_name, behavior_order, condition_type, action_type) VALUES
-  %s;
-
-INSERT INTO evolution_rules (personality_name, rule_order, trigger_type, effect_type) VALUES
-  %s;|} 
-        p.name p.name traits_sql knowledge_sql behaviors_sql evolution_sql in
+      let evolution_sql = List.mapi p.evolution ~f:(fun i evolution ->
+        Printf.sprintf "(%s, %d, %s, %s)" 
+          name_sql i (q (show_evolution_trigger evolution.trigger)) (q (show_evolution_effect evolution.action))) in
+
+      let ddl = [
+        sql_create_table options ~table:"personalities"
+          ~columns:[Printf.sprintf "name %s PRIMARY KEY" str;
+                    Printf.sprintf "created_at %s DEFAULT CURRENT_TIMESTAMP" (sql_timestamp_type options)]
+          ~constraints:[];
+        sql_create_table options ~table:"traits"
+          ~columns:["personality_name " ^ str; "trait_order INTEGER"; "trait_name " ^ str;
+                    "strength " ^ sql_float_type options; "modifiers TEXT"]
+          ~constraints:["PRIMARY KEY (personality_name, trait_name)"];
+        sql_create_table options ~table:"knowledge_topics"
+          ~columns:["personality_name " ^ str; "domain_name " ^ str; "topic_order INTEGER";
+                    "topic_name " ^ str; "level " ^ sql_short_string_type options]
+          ~constraints:["PRIMARY KEY (personality_name, domain_name, topic_name)"];
+        sql_create_table options ~table:"behaviors"
+          ~columns:["personality_name " ^ str; "behavior_order INTEGER"; "condition_type TEXT"; "action_type TEXT"]
+          ~constraints:["PRIMARY KEY (personality_name, behavior_order)"];
+        sql_create_table options ~table:"evolution_rules"
+          ~columns:["personality_name " ^ str; "rule_order INTEGER"; "trigger_type TEXT"; "effect_type TEXT"]
+          ~constraints:["PRIMARY KEY (personality_name, rule_order)"];
+      ] in
+
+      let inserts = [
+        sql_insert options ~table:"personalities" ~columns:["name"] ~key:["name"] [Printf.sprintf "(%s)" name_sql];
+        sql_insert options ~table:"traits"
+          ~columns:["personality_name"; "trait_order"; "trait_name"; "strength"; "modifiers"]
+          ~key:["personality_name"; "trait_name"] traits_sql;
+        sql_insert options ~table:"knowledge_topics"
+          ~columns:["personality_name"; "domain_name"; "topic_order"; "topic_name"; "level"]
+          ~key:["personality_name"; "domain_name"; "topic_name"] knowledge_sql;
+        sql_insert options ~table:"behaviors"
+          ~columns:["personality_name"; "behavior_order"; "condition_type"; "action_type"]
+          ~key:["personality_name"; "behavior_order"] behaviors_sql;
+        sql_insert options ~table:"evolution_rules"
+          ~columns:["personality_name"; "rule_order"; "trigger_type"; "effect_type"]
+          ~key:["personality_name"; "rule_order"] evolution_sql;
+      ] in
+
+      let ddl_section =
+        if options.inserts_only then ""
+        else Printf.sprintf "-- Create tables if they don't exist\n%s\n\n" (String.concat ~sep:"\n\n" ddl) in
+
+      let sql = Printf.sprintf "-- Generated SQL for Personality: %s\n\n%s-- Insert data\n%s"
+        (single_line p.name) ddl_section (String.concat ~sep:"\n\n" inserts) in
       Ok sql
 
 (** Cypher compilation *)
-let compile_to_cypher personality =
+let cypher_quote s =
+  let escaped = String.concat_map s ~f:(function
+    | '\'' -> "\\'"
+    | '\\' -> "\\\\"
+    | c -> String.of_char c) in
+  Printf.sprintf "'%s'" escaped
+
+let compile_to_cypher ?(options = default_cypher_options) personality =
   match validate_personality personality with
   | Error errors -> Error errors
   | Ok p ->
+      let verb = if options.use_merge then "MERGE" else "CREATE" in
+      let label l = options.label_prefix ^ l in
       let traits_cypher = String.concat ~sep:", " (List.map p.traits ~f:(fun t ->
         Printf.sprintf "%s: %f" t.name t.strength)) in
       
       let domains_cypher = String.concat ~sep:"\n" (List.map p.knowledge ~f:(fun domain ->
         let topics_cypher = String.concat ~sep:", " (List.map domain.topics ~f:(fun (topic, level) ->
           Printf.sprintf "%s: '%s'" topic (knowledge_level_to_string level))) in
-        Printf.sprintf "CREATE (d_%s:Domain {name: '%s', %s})" 
-          (String.filter domain.name ~f:Char.is_alphanum) domain.name topics_cypher)) in
+        Printf.sprintf "%s (d_%s:%s {name: %s, %s})" 
+          verb (String.filter domain.name ~f:Char.is_alphanum) (label "Domain") (cypher_quote domain.name) topics_cypher)) in
       
-      let connections_cypher = String.concat ~sep:"\n" (List.fold p.knowledge ~init:[] ~f:(fun acc domain ->
-        acc @ List.map domain.connections ~f:(fun conn ->
-          Printf.sprintf "CREATE (d_%s)-[:CONNECTS {strength: %f}]->(d_%s)"
+      let connections_cypher = String.concat ~sep:"\n" (List.concat_map p.knowledge ~f:(fun domain ->
+        List.map domain.connections ~f:(fun conn ->
+          Printf.sprintf "%s (d_%s)-[:CONNECTS {strength: %f}]->(d_%s)"
+            verb
             (String.filter conn.from_domain ~f:Char.is_alphanum)
             conn.strength
             (String.filter conn.to_domain ~f:Char.is_alphanum)))) in
       
       let behaviors_cypher = String.concat ~sep:"\n" (List.mapi p.behaviors ~f:(fun i behavior ->
-        Printf.sprintf "CREATE (b%d:Behavior {condition: '%s', action: '%s'})"
-          i (show_behavior_condition behavior.condition) (show_behavior_action behavior.action))) in
+        Printf.sprintf "%s (b%d:%s {condition: %s, action: %s})"
+          verb i (label "Behavior")
+          (cypher_quote (show_behavior_condition behavior.condition))
+          (cypher_quote (show_behavior_action behavior.action)))) in
       
       let evolution_cypher = String.concat ~sep:"\n" (List.mapi p.evolution ~f:(fun i evolution ->
-        Printf.sprintf "CREATE (e%d:Evolution {trigger: '%s', effect: '%s'})"
-          i (show_evolution_trigger evolution.trigger) (show_evolution_effect evolution.action))) in
+        Printf.sprintf "%s (e%d:%s {trigger: %s, effect: %s})"
+          verb i (label "Evolution")
+          (cypher_quote (show_evolution_trigger evolution.trigger))
+          (cypher_quote (show_evolution_effect evolution.action)))) in
       
       let cypher = Printf.sprintf {|// Generated Cypher for Personality: %s
 
 // Create personality node
-CREATE (p:Personality {name: '%s', %s})
+%s (p:%s {name: %s, %s})
 
 // Create knowledge domains
 %s
@@ -392,19 +496,196 @@ CREATE (p:Personality {name: '%s', %s})
 %s
 
 // Connect personality to its components
-CREATE (p)-[:HAS_DOMAIN]->(d_%s)
-CREATE (p)-[:HAS_BEHAVIOR]->(b0)
-CREATE (p)-[:HAS_EVOLUTION]->(e0)|} 
-        p.name p.name traits_cypher domains_cypher connections_cypher 
-        behaviors_cypher evolution_cypher 
-        (match p.knowledge with [] -> "none" | d::_ -> String.filter d.name ~f:Char.is_alphanum) in
+%s (p)-[:HAS_DOMAIN]->(d_%s)
+%s (p)-[:HAS_BEHAVIOR]->(b0)
+%s (p)-[:HAS_EVOLUTION]->(e0)|} 
+        (single_line p.name) verb (label "Personality") (cypher_quote p.name) traits_cypher
+        domains_cypher connections_cypher behaviors_cypher evolution_cypher 
+        verb (match p.knowledge with [] -> "none" | d::_ -> String.filter d.name ~f:Char.is_alphanum)
+        verb verb in
       Ok cypher
 
+(** YAML compilation *)
+let yaml_quote s =
+  let escaped = String.concat_map s ~f:(function
+    | '"' -> "\\\""
+    | '\\' -> "\\\\"
+    | '\n' -> "\\n"
+    | '\r' -> "\\r"
+    | '\t' -> "\\t"
+    | c when Char.to_int c < 0x20 || Char.to_int c = 0x7f ->
+        Printf.sprintf "\\x%02x" (Char.to_int c)
+    | c -> String.of_char c) in
+  Printf.sprintf "\"%s\"" escaped
+
+let trait_modifier_to_yaml modifier =
+  match modifier with
+  | Types.Decay (rate, unit) ->
+      Printf.sprintf "{type: decay, rate: %f, unit: %s}" rate (time_unit_to_string unit)
+  | Types.When ctx ->
+      Printf.sprintf "{type: when, context: %s}" (yaml_quote (context_to_string ctx))
+  | Types.Unless ctx ->
+      Printf.sprintf "{type: unless, context: %s}" (yaml_quote (context_to_string ctx))
+  | Types.Amplifies (trait, factor) ->
+      Printf.sprintf "{type: amplifies, trait: %s, factor: %f}" (yaml_quote trait) factor
+  | Types.Transforms_to (trait, factor, count) ->
+      Printf.sprintf "{type: transforms_to, trait: %s, factor: %f, count: %d}"
+        (yaml_quote trait) factor count
+
+let behavior_condition_to_yaml = function
+  | Types.Tired -> "{type: tired}"
+  | Types.Motivated -> "{type: motivated}"
+  | Types.Context_match ctx ->
+      Printf.sprintf "{type: context_match, context: %s}" (yaml_quote ctx)
+  | Types.Trait_above (trait, threshold) ->
+      Printf.sprintf "{type: trait_above, trait: %s, threshold: %f}" (yaml_quote trait) threshold
+  | Types.Time_range (start, end_) ->
+      Printf.sprintf "{type: time_range, start: %s, end: %s}" (yaml_quote start) (yaml_quote end_)
+
+let behavior_action_to_yaml = function
+  | Types.Prefer value -> Printf.sprintf "{type: prefer, value: %s}" (yaml_quote value)
+  | Types.Seek value -> Printf.sprintf "{type: seek, value: %s}" (yaml_quote value)
+  | Types.Avoid value -> Printf.sprintf "{type: avoid, value: %s}" (yaml_quote value)
+  | Types.Set_style (style, value) ->
+      Printf.sprintf "{type: set_style, style: %s, value: %s}" (yaml_quote style) (yaml_quote value)
+
+let evolution_trigger_to_yaml = function
+  | Types.Learns topic -> Printf.sprintf "{type: learns, topic: %s}" (yaml_quote topic)
+  | Types.Time_in_domain (domain, unit, duration) ->
+      Printf.sprintf "{type: time_in_domain, domain: %s, unit: %s, duration: %d}"
+        (yaml_quote domain) (time_unit_to_string unit) duration
+  | Types.Interaction_count count -> Printf.sprintf "{type: interaction_count, count: %d}" count
+  | Types.Feedback_score score -> Printf.sprintf "{type: feedback_score, score: %f}" score
+
+let evolution_effect_to_yaml = function
+  | Types.Trait_adjust (trait, delta) ->
+      Printf.sprintf "{type: trait_adjust, trait: %s, delta: %f}" (yaml_quote trait) delta
+  | Types.Unlock_domain domain -> Printf.sprintf "{type: unlock_domain, domain: %s}" (yaml_quote domain)
+  | Types.Add_connection (from_d, to_d, strength) ->
+      Printf.sprintf "{type: add_connection, from: %s, to: %s, strength: %f}"
+        (yaml_quote from_d) (yaml_quote to_d) strength
+  | Types.New_behavior rule ->
+      Printf.sprintf "{type: new_behavior, condition: %s, action: %s}"
+        (behavior_condition_to_yaml rule.condition) (behavior_action_to_yaml rule.action)
+
+let yaml_list ~indent items =
+  match items with
+  | [] -> " []"
+  | items ->
+      let pad = String.init indent ~f:(fun _ -> ' ') in
+      "\n" ^ String.concat ~sep:"\n" (List.map items ~f:(fun item -> pad ^ "- " ^ item))
+
+let compile_to_yaml personality =
+  match validate_personality personality with
+  | Error errors -> Error errors
+  | Ok p ->
+      let traits_yaml = yaml_list ~indent:2 (List.map p.traits ~f:(fun t ->
+        Printf.sprintf "name: %s\n    strength: %f\n    modifiers:%s"
+          (yaml_quote t.name) t.strength
+          (yaml_list ~indent:6 (List.map t.modifiers ~f:trait_modifier_to_yaml)))) in
+
+      let knowledge_yaml = yaml_list ~indent:2 (List.map p.knowledge ~f:(fun d ->
+        let topics_yaml = yaml_list ~indent:6 (List.map d.topics ~f:(fun (name, level) ->
+          Printf.sprintf "{name: %s, level: %s}" (yaml_quote name) (knowledge_level_to_string level))) in
+        let connections_yaml = yaml_list ~indent:6 (List.map d.connections ~f:(fun conn ->
+          Printf.sprintf "{from: %s, to: %s, strength: %f}"
+            (yaml_quote conn.from_domain) (yaml_quote conn.to_domain) conn.strength)) in
+        Printf.sprintf "name: %s\n    topics:%s\n    connections:%s"
+          (yaml_quote d.name) topics_yaml connections_yaml)) in
+
+      let behaviors_yaml = yaml_list ~indent:2 (List.map p.behaviors ~f:(fun behavior ->
+        Printf.sprintf "condition: %s\n    action: %s"
+          (behavior_condition_to_yaml behavior.condition)
+          (behavior_action_to_yaml behavior.action))) in
+
+      let evolution_yaml = yaml_list ~indent:2 (List.map p.evolution ~f:(fun evolution ->
+        Printf.sprintf "trigger: %s\n    effect: %s"
+          (evolution_trigger_to_yaml evolution.trigger)
+          (evolution_effect_to_yaml evolution.action))) in
+
+      let yaml = Printf.sprintf {|# Generated YAML for Personality: %s
+name: %s
+traits:%s
+knowledge:%s
+behaviors:%s
+evolution:%s
+|}
+        (single_line p.name) (yaml_quote p.name) traits_yaml knowledge_yaml behaviors_yaml evolution_yaml in
+      Ok yaml
+
+(** Markdown compilation *)
+let strength_bar strength =
+  let filled = Float.iround_nearest_exn (strength *. 10.0) in
+  String.init 10 ~f:(fun i -> if i < filled then '#' else '.')
+
+let markdown_cell s =
+  String.substr_replace_all (single_line s) ~pattern:"|" ~with_:"\\|"
+
+let compile_to_markdown personality =
+  match validate_personality personality with
+  | Error errors -> Error errors
+  | Ok p ->
+      let traits_md = match p.traits with
+        | [] -> "_No traits defined._"
+        | traits -> String.concat ~sep:"\n" (List.map traits ~f:(fun t ->
+            let modifiers = match t.modifiers with
+              | [] -> ""
+              | mods -> " — " ^ String.concat ~sep:", " (List.map mods ~f:trait_modifier_to_string) in
+            Printf.sprintf "- **%s** `[%s]` %.2f%s" t.name (strength_bar t.strength) t.strength modifiers)) in
+
+      let knowledge_md = match p.knowledge with
+        | [] -> "_No knowledge domains defined._"
+        | domains -> String.concat ~sep:"\n" (List.map domains ~f:(fun d ->
+            let topics = List.map d.topics ~f:(fun (topic, level) ->
+              Printf.sprintf "  - %s (%s)" topic (knowledge_level_to_string level)) in
+            let connections = List.map d.connections ~f:(fun conn ->
+              Printf.sprintf "  - connects to %s (strength %.2f)" conn.to_domain conn.strength) in
+            String.concat ~sep:"\n" ((Printf.sprintf "- **%s**" d.name) :: topics @ connections))) in
+
+      let behaviors_md = match p.behaviors with
+        | [] -> "_No behaviors defined._"
+        | behaviors -> "| # | Condition | Action |\n|---|-----------|--------|\n" ^
+            String.concat ~sep:"\n" (List.mapi behaviors ~f:(fun i behavior ->
+              Printf.sprintf "| %d | %s | %s |" (i + 1)
+                (markdown_cell (behavior_condition_to_string behavior.condition))
+                (markdown_cell (behavior_action_to_string behavior.action)))) in
+
+      let evolution_md = match p.evolution with
+        | [] -> "_No evolution rules defined._"
+        | evolutions -> "| # | Trigger | Effect |\n|---|---------|--------|\n" ^
+            String.concat ~sep:"\n" (List.mapi evolutions ~f:(fun i evolution ->
+              Printf.sprintf "| %d | %s | %s |" (i + 1)
+                (markdown_cell (evolution_trigger_to_string evolution.trigger))
+                (markdown_cell (evolution_effect_to_string evolution.action)))) in
+
+      let markdown = Printf.sprintf {|# Personality: %s
+
+## Traits
+
+%s
+
+## Knowledge Domains
+
+%s
+
+## Behaviors
+
+%s
+
+## Evolution
+
+%s
+|}
+        p.name traits_md knowledge_md behaviors_md evolution_md in
+      Ok markdown
+
 (** Main compilation function *)
-let compile personality target ?context () = 
+let compile personality target ?context ?(sql = default_sql_options) ?(cypher = default_cypher_options) () = 
   match target with
   | Json -> compile_to_json personality
   | Lua -> compile_to_lua personality
   | Prompt -> compile_to_prompt personality context
-  | Sql -> compile_to_sql personality
-  | Cypher -> compile_to_cypher personality
+  | Sql -> compile_to_sql ~options:sql personality
+  | Cypher -> compile_to_cypher ~options:cypher personality
+  | Yaml -> compile_to_yaml personality
+  | Markdown -> compile_to_markdown personality
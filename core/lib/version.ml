@@ -0,0 +1,47 @@
+(** Parser version and capability information
+
+    Lets embedders (the CLI, the Python wrapper, native bridges) detect which
+    parser build they are linked against and what it supports, instead of
+    assuming a hard-coded version. *)
+
+(** Semantic version of the parser library *)
+let version = "0.2.0"
+
+(** Build metadata reported by [info] *)
+type info = {
+  version: string;           (** Semantic version of the parser library *)
+  git_commit: string;        (** [git describe] of the build, or "unknown" *)
+  build_date: string;        (** UTC build date (YYYY-MM-DD), or "unknown" *)
+  features: string list;     (** Supported DSL feature flags *)
+  targets: string list;      (** Compile targets accepted by [Compiler.compile] *)
+} [@@deriving show, eq]
+
+(** DSL language features understood by this parser *)
+let features = [
+  "traits";
+  "trait_modifiers";
+  "knowledge_domains";
+  "domain_connections";
+  "behaviors";
+  "evolution";
+  "unicode_arrows";
+  "optional_punctuation";
+]
+
+(** Compile targets, named as on the dsl-parser command line *)
+let targets = ["json"; "lua"; "prompt"; "sql"; "cypher"; "yaml"; "markdown"]
+
+(** Version string with the [git describe] suffix when the build knows it,
+    e.g. "0.2.0+v0.2.0-3-g1a2b3c4" *)
+let get () =
+  match Build_info.git_describe with
+  | "unknown" -> version
+  | describe -> version ^ "+" ^ describe
+
+let info () = {
+  version;
+  git_commit = Build_info.git_describe;
+  build_date = Build_info.build_date;
+  features;
+  targets;
+}
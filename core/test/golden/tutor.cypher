@@ -0,0 +1,24 @@
+// Generated Cypher for Personality: Tutor "Q" \ v1
+
+// Create personality node
+CREATE (p:Personality {name: 'Tutor "Q" \\ v1', empathy: 0.900000, patience: 0.700000})
+
+// Create knowledge domains
+CREATE (d_education:Domain {name: 'education', pedagogy: 'expert', assessment: 'intermediate'})
+CREATE (d_communication:Domain {name: 'communication', listening: 'advanced'})
+
+// Create connections
+CREATE (d_education)-[:CONNECTS {strength: 0.800000}]->(d_communication)
+
+// Create behaviors
+CREATE (b0:Behavior {condition: '(Types.Trait_above ("empathy", 0.8))', action: '(Types.Seek "student emotions")'})
+CREATE (b1:Behavior {condition: '(Types.Context_match "a|b")', action: '(Types.Set_style ("tone", "warm"))'})
+
+// Create evolution rules
+CREATE (e0:Evolution {trigger: '(Types.Learns "learning_style")', effect: '(Types.Trait_adjust ("patience", 0.1))'})
+CREATE (e1:Evolution {trigger: '(Types.Interaction_count 50)', effect: '(Types.Unlock_domain "communication")'})
+
+// Connect personality to its components
+CREATE (p)-[:HAS_DOMAIN]->(d_education)
+CREATE (p)-[:HAS_BEHAVIOR]->(b0)
+CREATE (p)-[:HAS_EVOLUTION]->(e0)
\ No newline at end of file
@@ -0,0 +1,24 @@
+// Generated Cypher for Personality: Tutor "Q" \ v1
+
+// Create personality node
+MERGE (p:CallosumPersonality {name: 'Tutor "Q" \\ v1', empathy: 0.900000, patience: 0.700000})
+
+// Create knowledge domains
+MERGE (d_education:CallosumDomain {name: 'education', pedagogy: 'expert', assessment: 'intermediate'})
+MERGE (d_communication:CallosumDomain {name: 'communication', listening: 'advanced'})
+
+// Create connections
+MERGE (d_education)-[:CONNECTS {strength: 0.800000}]->(d_communication)
+
+// Create behaviors
+MERGE (b0:CallosumBehavior {condition: '(Types.Trait_above ("empathy", 0.8))', action: '(Types.Seek "student emotions")'})
+MERGE (b1:CallosumBehavior {condition: '(Types.Context_match "a|b")', action: '(Types.Set_style ("tone", "warm"))'})
+
+// Create evolution rules
+MERGE (e0:CallosumEvolution {trigger: '(Types.Learns "learning_style")', effect: '(Types.Trait_adjust ("patience", 0.1))'})
+MERGE (e1:CallosumEvolution {trigger: '(Types.Interaction_count 50)', effect: '(Types.Unlock_domain "communication")'})
+
+// Connect personality to its components
+MERGE (p)-[:HAS_DOMAIN]->(d_education)
+MERGE (p)-[:HAS_BEHAVIOR]->(b0)
+MERGE (p)-[:HAS_EVOLUTION]->(e0)
\ No newline at end of file
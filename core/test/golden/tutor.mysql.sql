@@ -0,0 +1,27 @@
+-- Generated SQL for Personality: Tutor "Q" \ v1
+
+-- Insert data
+INSERT INTO personalities (name) VALUES
+  ('Tutor "Q" \\ v1')
+ON DUPLICATE KEY UPDATE name = name;
+
+INSERT INTO traits (personality_name, trait_order, trait_name, strength, modifiers) VALUES
+  ('Tutor "Q" \\ v1', 0, 'empathy', 0.900000, '(Types.Amplifies ("patience", 1.5));(Types.When (Types.Situation "student_confused"))'),
+  ('Tutor "Q" \\ v1', 1, 'patience', 0.700000, '(Types.Decay (0.05, Types.Week))')
+ON DUPLICATE KEY UPDATE trait_order = VALUES(trait_order), strength = VALUES(strength), modifiers = VALUES(modifiers);
+
+INSERT INTO knowledge_topics (personality_name, domain_name, topic_order, topic_name, level) VALUES
+  ('Tutor "Q" \\ v1', 'education', 0, 'pedagogy', 'expert'),
+  ('Tutor "Q" \\ v1', 'education', 1, 'assessment', 'intermediate'),
+  ('Tutor "Q" \\ v1', 'communication', 0, 'listening', 'advanced')
+ON DUPLICATE KEY UPDATE topic_order = VALUES(topic_order), level = VALUES(level);
+
+INSERT INTO behaviors (personality_name, behavior_order, condition_type, action_type) VALUES
+  ('Tutor "Q" \\ v1', 0, '(Types.Trait_above ("empathy", 0.8))', '(Types.Seek "student emotions")'),
+  ('Tutor "Q" \\ v1', 1, '(Types.Context_match "a|b")', '(Types.Set_style ("tone", "warm"))')
+ON DUPLICATE KEY UPDATE condition_type = VALUES(condition_type), action_type = VALUES(action_type);
+
+INSERT INTO evolution_rules (personality_name, rule_order, trigger_type, effect_type) VALUES
+  ('Tutor "Q" \\ v1', 0, '(Types.Learns "learning_style")', '(Types.Trait_adjust ("patience", 0.1))'),
+  ('Tutor "Q" \\ v1', 1, '(Types.Interaction_count 50)', '(Types.Unlock_domain "communication")')
+ON DUPLICATE KEY UPDATE trigger_type = VALUES(trigger_type), effect_type = VALUES(effect_type);
\ No newline at end of file
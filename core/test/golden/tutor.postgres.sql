@@ -0,0 +1,71 @@
+-- Generated SQL for Personality: Tutor "Q" \ v1
+
+-- Create tables if they don't exist
+CREATE TABLE IF NOT EXISTS personalities (
+  name VARCHAR(255) PRIMARY KEY,
+  created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS traits (
+  personality_name VARCHAR(255),
+  trait_order INTEGER,
+  trait_name VARCHAR(255),
+  strength DOUBLE PRECISION,
+  modifiers TEXT,
+  PRIMARY KEY (personality_name, trait_name),
+  FOREIGN KEY (personality_name) REFERENCES personalities(name)
+);
+
+CREATE TABLE IF NOT EXISTS knowledge_topics (
+  personality_name VARCHAR(255),
+  domain_name VARCHAR(255),
+  topic_order INTEGER,
+  topic_name VARCHAR(255),
+  level VARCHAR(50),
+  PRIMARY KEY (personality_name, domain_name, topic_name),
+  FOREIGN KEY (personality_name) REFERENCES personalities(name)
+);
+
+CREATE TABLE IF NOT EXISTS behaviors (
+  personality_name VARCHAR(255),
+  behavior_order INTEGER,
+  condition_type TEXT,
+  action_type TEXT,
+  PRIMARY KEY (personality_name, behavior_order),
+  FOREIGN KEY (personality_name) REFERENCES personalities(name)
+);
+
+CREATE TABLE IF NOT EXISTS evolution_rules (
+  personality_name VARCHAR(255),
+  rule_order INTEGER,
+  trigger_type TEXT,
+  effect_type TEXT,
+  PRIMARY KEY (personality_name, rule_order),
+  FOREIGN KEY (personality_name) REFERENCES personalities(name)
+);
+
+-- Insert data
+INSERT INTO personalities (name) VALUES
+  ('Tutor "Q" \ v1')
+ON CONFLICT (name) DO NOTHING;
+
+INSERT INTO traits (personality_name, trait_order, trait_name, strength, modifiers) VALUES
+  ('Tutor "Q" \ v1', 0, 'empathy', 0.900000, '(Types.Amplifies ("patience", 1.5));(Types.When (Types.Situation "student_confused"))'),
+  ('Tutor "Q" \ v1', 1, 'patience', 0.700000, '(Types.Decay (0.05, Types.Week))')
+ON CONFLICT (personality_name, trait_name) DO UPDATE SET trait_order = EXCLUDED.trait_order, strength = EXCLUDED.strength, modifiers = EXCLUDED.modifiers;
+
+INSERT INTO knowledge_topics (personality_name, domain_name, topic_order, topic_name, level) VALUES
+  ('Tutor "Q" \ v1', 'education', 0, 'pedagogy', 'expert'),
+  ('Tutor "Q" \ v1', 'education', 1, 'assessment', 'intermediate'),
+  ('Tutor "Q" \ v1', 'communication', 0, 'listening', 'advanced')
+ON CONFLICT (personality_name, domain_name, topic_name) DO UPDATE SET topic_order = EXCLUDED.topic_order, level = EXCLUDED.level;
+
+INSERT INTO behaviors (personality_name, behavior_order, condition_type, action_type) VALUES
+  ('Tutor "Q" \ v1', 0, '(Types.Trait_above ("empathy", 0.8))', '(Types.Seek "student emotions")'),
+  ('Tutor "Q" \ v1', 1, '(Types.Context_match "a|b")', '(Types.Set_style ("tone", "warm"))')
+ON CONFLICT (personality_name, behavior_order) DO UPDATE SET condition_type = EXCLUDED.condition_type, action_type = EXCLUDED.action_type;
+
+INSERT INTO evolution_rules (personality_name, rule_order, trigger_type, effect_type) VALUES
+  ('Tutor "Q" \ v1', 0, '(Types.Learns "learning_style")', '(Types.Trait_adjust ("patience", 0.1))'),
+  ('Tutor "Q" \ v1', 1, '(Types.Interaction_count 50)', '(Types.Unlock_domain "communication")')
+ON CONFLICT (personality_name, rule_order) DO UPDATE SET trigger_type = EXCLUDED.trigger_type, effect_type = EXCLUDED.effect_type;
\ No newline at end of file
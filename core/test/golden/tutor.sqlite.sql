@@ -0,0 +1,66 @@
+-- Generated SQL for Personality: Tutor "Q" \ v1
+
+-- Create tables if they don't exist
+CREATE TABLE IF NOT EXISTS cal_personalities (
+  name TEXT PRIMARY KEY,
+  created_at TEXT DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS cal_traits (
+  personality_name TEXT,
+  trait_order INTEGER,
+  trait_name TEXT,
+  strength REAL,
+  modifiers TEXT,
+  PRIMARY KEY (personality_name, trait_name),
+  FOREIGN KEY (personality_name) REFERENCES cal_personalities(name)
+);
+
+CREATE TABLE IF NOT EXISTS cal_knowledge_topics (
+  personality_name TEXT,
+  domain_name TEXT,
+  topic_order INTEGER,
+  topic_name TEXT,
+  level TEXT,
+  PRIMARY KEY (personality_name, domain_name, topic_name),
+  FOREIGN KEY (personality_name) REFERENCES cal_personalities(name)
+);
+
+CREATE TABLE IF NOT EXISTS cal_behaviors (
+  personality_name TEXT,
+  behavior_order INTEGER,
+  condition_type TEXT,
+  action_type TEXT,
+  PRIMARY KEY (personality_name, behavior_order),
+  FOREIGN KEY (personality_name) REFERENCES cal_personalities(name)
+);
+
+CREATE TABLE IF NOT EXISTS cal_evolution_rules (
+  personality_name TEXT,
+  rule_order INTEGER,
+  trigger_type TEXT,
+  effect_type TEXT,
+  PRIMARY KEY (personality_name, rule_order),
+  FOREIGN KEY (personality_name) REFERENCES cal_personalities(name)
+);
+
+-- Insert data
+INSERT OR REPLACE INTO cal_personalities (name) VALUES
+  ('Tutor "Q" \ v1');
+
+INSERT OR REPLACE INTO cal_traits (personality_name, trait_order, trait_name, strength, modifiers) VALUES
+  ('Tutor "Q" \ v1', 0, 'empathy', 0.900000, '(Types.Amplifies ("patience", 1.5));(Types.When (Types.Situation "student_confused"))'),
+  ('Tutor "Q" \ v1', 1, 'patience', 0.700000, '(Types.Decay (0.05, Types.Week))');
+
+INSERT OR REPLACE INTO cal_knowledge_topics (personality_name, domain_name, topic_order, topic_name, level) VALUES
+  ('Tutor "Q" \ v1', 'education', 0, 'pedagogy', 'expert'),
+  ('Tutor "Q" \ v1', 'education', 1, 'assessment', 'intermediate'),
+  ('Tutor "Q" \ v1', 'communication', 0, 'listening', 'advanced');
+
+INSERT OR REPLACE INTO cal_behaviors (personality_name, behavior_order, condition_type, action_type) VALUES
+  ('Tutor "Q" \ v1', 0, '(Types.Trait_above ("empathy", 0.8))', '(Types.Seek "student emotions")'),
+  ('Tutor "Q" \ v1', 1, '(Types.Context_match "a|b")', '(Types.Set_style ("tone", "warm"))');
+
+INSERT OR REPLACE INTO cal_evolution_rules (personality_name, rule_order, trigger_type, effect_type) VALUES
+  ('Tutor "Q" \ v1', 0, '(Types.Learns "learning_style")', '(Types.Trait_adjust ("patience", 0.1))'),
+  ('Tutor "Q" \ v1', 1, '(Types.Interaction_count 50)', '(Types.Unlock_domain "communication")');
\ No newline at end of file
@@ -107,6 +107,96 @@ let test_lua_compilation () =
       Alcotest.(check bool) "lua contains trait" true (String.is_substring lua ~substring:"creativity")
   | Error _ -> Alcotest.fail "Lua compilation should succeed"
 
+(* Fully populated personality shared by the YAML and Markdown golden tests *)
+let golden_personality = Types.{
+  name = "Tutor \"Q\" \\ v1";
+  traits = [
+    { name = "empathy"; strength = 0.9;
+      modifiers = [Amplifies ("patience", 1.5); When (Situation "student_confused")] };
+    { name = "patience"; strength = 0.7; modifiers = [Decay (0.05, Week)] };
+  ];
+  knowledge = [
+    { name = "education";
+      topics = [("pedagogy", Expert); ("assessment", Intermediate)];
+      connections = [{ from_domain = "education"; to_domain = "communication";
+                       strength = 0.8; evolution_rate = None }] };
+    { name = "communication"; topics = [("listening", Advanced)]; connections = [] };
+  ];
+  behaviors = [
+    { condition = Trait_above ("empathy", 0.8); action = Seek "student emotions" };
+    { condition = Context_match "a|b"; action = Set_style ("tone", "warm") };
+  ];
+  evolution = [
+    { trigger = Learns "learning_style"; action = Trait_adjust ("patience", 0.1) };
+    { trigger = Interaction_count 50; action = Unlock_domain "communication" };
+  ];
+}
+
+let read_golden name = Stdio.In_channel.read_all (Stdlib.Filename.concat "golden" name)
+
+let test_yaml_compilation () = 
+  match Compiler.compile golden_personality Yaml () with
+  | Ok yaml -> Alcotest.(check string) "yaml output" (read_golden "tutor.yaml") yaml
+  | Error _ -> Alcotest.fail "YAML compilation should succeed"
+
+let test_markdown_compilation () = 
+  match Compiler.compile golden_personality Markdown () with
+  | Ok md -> Alcotest.(check string) "markdown output" (read_golden "tutor.md") md
+  | Error _ -> Alcotest.fail "Markdown compilation should succeed"
+
+let check_sql_golden ~golden options () =
+  match Compiler.compile golden_personality Sql ~sql:options () with
+  | Ok sql -> Alcotest.(check string) "sql output" (read_golden golden) sql
+  | Error _ -> Alcotest.fail "SQL compilation should succeed"
+
+let check_cypher_golden ~golden options () =
+  match Compiler.compile golden_personality Cypher ~cypher:options () with
+  | Ok cypher -> Alcotest.(check string) "cypher output" (read_golden golden) cypher
+  | Error _ -> Alcotest.fail "Cypher compilation should succeed"
+
+let test_sql_generic =
+  check_sql_golden ~golden:"tutor.generic.sql" Compiler.default_sql_options
+
+let test_sql_postgres =
+  check_sql_golden ~golden:"tutor.postgres.sql"
+    { Compiler.default_sql_options with Compiler.dialect = Compiler.Postgres }
+
+let test_sql_sqlite_prefixed =
+  check_sql_golden ~golden:"tutor.sqlite.sql"
+    { Compiler.default_sql_options with Compiler.dialect = Compiler.Sqlite; table_prefix = "cal_" }
+
+let test_sql_mysql_inserts_only =
+  check_sql_golden ~golden:"tutor.mysql.sql"
+    { Compiler.default_sql_options with Compiler.dialect = Compiler.Mysql; inserts_only = true }
+
+let test_cypher_create =
+  check_cypher_golden ~golden:"tutor.cypher" Compiler.default_cypher_options
+
+let test_cypher_merge_prefixed =
+  check_cypher_golden ~golden:"tutor.merge.cypher"
+    Compiler.{ label_prefix = "Callosum"; use_merge = true }
+
+let test_sql_dialect_of_string () =
+  Alcotest.(check bool) "postgresql alias" true
+    (Option.is_some (Compiler.sql_dialect_of_string "PostgreSQL"));
+  Alcotest.(check bool) "unknown dialect rejected" true
+    (Option.is_none (Compiler.sql_dialect_of_string "oracle"))
+
+let test_yaml_quote_escapes () =
+  Alcotest.(check string) "control characters escaped"
+    {|"a\rb\tc\x01d\"e\\f\ng"|} (Compiler.yaml_quote "a\rb\tc\x01d\"e\\f\ng")
+
+let test_yaml_header_single_line () =
+  let personality = Ast.create_personality "Evil\nbad: injected" in
+  match Compiler.compile personality Yaml () with
+  | Ok yaml ->
+      let lines = String.split_lines yaml in
+      Alcotest.(check string) "header stays a comment"
+        "# Generated YAML for Personality: Evil bad: injected" (List.hd_exn lines);
+      Alcotest.(check bool) "no injected key" false
+        (List.exists lines ~f:(String.is_prefix ~prefix:"bad:"))
+  | Error _ -> Alcotest.fail "YAML compilation should succeed"
+
 let test_trait_validation () = 
   let personality = Ast.create_personality "Test" in
   let invalid_trait = Types.{ name = "invalid"; strength = 1.5; modifiers = [] } in
@@ -268,6 +358,33 @@ evolution:
       let error_messages = String.concat ~sep:"; " (List.map errors ~f:(fun e -> e.message)) in
       Alcotest.fail ("Parse failed: " ^ error_messages)
 
+(* Test version and capability information *)
+let test_version_targets () =
+  (* Exhaustive on purpose: a new Compiler.target constructor must be named here
+     and advertised by Version.info before this compiles again *)
+  let target_name = function
+    | Compiler.Json -> "json"
+    | Compiler.Lua -> "lua"
+    | Compiler.Prompt -> "prompt"
+    | Compiler.Sql -> "sql"
+    | Compiler.Cypher -> "cypher"
+    | Compiler.Yaml -> "yaml"
+    | Compiler.Markdown -> "markdown" in
+  let all_targets = Compiler.[Json; Lua; Prompt; Sql; Cypher; Yaml; Markdown] in
+  let expected = List.sort (List.map all_targets ~f:target_name) ~compare:String.compare in
+  let advertised = List.sort (Version.info ()).Version.targets ~compare:String.compare in
+  Alcotest.(check (list string)) "advertised targets" expected advertised
+
+let test_version_info () =
+  let info = Version.info () in
+  Alcotest.(check string) "version" Version.version info.Version.version;
+  Alcotest.(check bool) "git commit set" false (String.is_empty info.Version.git_commit);
+  Alcotest.(check bool) "features listed" true (List.mem info.Version.features "evolution" ~equal:String.equal)
+
+let test_version_get () =
+  Alcotest.(check bool) "starts with library version" true
+    (String.is_prefix (Version.get ()) ~prefix:Version.version)
+
 let () = 
   let open Alcotest in
   run "DSL Parser Tests" [
@@ -287,6 +404,17 @@ let () =
     "compiler", [
       test_case "json compilation" `Quick test_json_compilation;
       test_case "lua compilation" `Quick test_lua_compilation;
+      test_case "yaml compilation" `Quick test_yaml_compilation;
+      test_case "markdown compilation" `Quick test_markdown_compilation;
+      test_case "yaml quote escapes" `Quick test_yaml_quote_escapes;
+      test_case "yaml header single line" `Quick test_yaml_header_single_line;
+      test_case "sql generic" `Quick test_sql_generic;
+      test_case "sql postgres" `Quick test_sql_postgres;
+      test_case "sql sqlite with table prefix" `Quick test_sql_sqlite_prefixed;
+      test_case "sql mysql inserts only" `Quick test_sql_mysql_inserts_only;
+      test_case "sql dialect parsing" `Quick test_sql_dialect_of_string;
+      test_case "cypher create" `Quick test_cypher_create;
+      test_case "cypher merge with label prefix" `Quick test_cypher_merge_prefixed;
       test_case "trait validation" `Quick test_trait_validation;
     ];
     "semantic_analysis", [
@@ -298,4 +426,9 @@ let () =
       test_case "warnings generation" `Quick test_warnings_generation;
       test_case "valid personality passes" `Quick test_valid_personality_passes;
     ];
+    "version", [
+      test_case "targets match compiler" `Quick test_version_targets;
+      test_case "build info" `Quick test_version_info;
+      test_case "version string" `Quick test_version_get;
+    ];
   ]
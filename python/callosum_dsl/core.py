@@ -365,14 +365,16 @@ class Callosum:
         return None
     
     def compile(self, dsl_content: str, target: str = "json", 
-                context: Optional[str] = None) -> str:
+                context: Optional[str] = None,
+                extra_args: Optional[List[str]] = None) -> str:
         """
         Compile DSL content to target format
         
         Args:
             dsl_content: The personality DSL as string
-            target: Output format (json, prompt, lua, sql, cypher)
+            target: Output format (json, prompt, lua, sql, cypher, yaml, markdown)
             context: Optional context hint for prompt generation
+            extra_args: Additional dsl-parser flags (e.g. ["--sql-dialect", "sqlite"])
             
         Returns:
             Compiled output as string
@@ -390,6 +392,9 @@ class Callosum:
         if context:
             cmd.extend(["--context", context])
         
+        if extra_args:
+            cmd.extend(extra_args)
+        
         # Use stdin for DSL content
         cmd.extend(["--input", "-"])
         
@@ -461,29 +466,70 @@ class Callosum:
         """
         return self.compile(dsl_content, "lua")
     
-    def to_sql(self, dsl_content: str) -> str:
+    def to_sql(self, dsl_content: str, dialect: str = "generic",
+               table_prefix: str = "", inserts_only: bool = False) -> str:
         """
         Compile DSL to SQL schema and data
         
         Args:
             dsl_content: The personality DSL as string
+            dialect: SQL dialect (generic, postgres, sqlite, mysql)
+            table_prefix: Prefix for every table name
+            inserts_only: Emit inserts without CREATE TABLE statements
             
         Returns:
             SQL statements as string
         """
-        return self.compile(dsl_content, "sql")
-    
-    def to_cypher(self, dsl_content: str) -> str:
+        args = ["--sql-dialect", dialect]
+        if table_prefix:
+            args.extend(["--table-prefix", table_prefix])
+        if inserts_only:
+            args.append("--inserts-only")
+        return self.compile(dsl_content, "sql", extra_args=args)
+    
+    def to_cypher(self, dsl_content: str, label_prefix: str = "",
+                  merge: bool = False) -> str:
         """
         Compile DSL to Cypher queries for Neo4j
         
         Args:
             dsl_content: The personality DSL as string
+            label_prefix: Prefix for every node label
+            merge: Emit MERGE instead of CREATE
             
         Returns:
             Cypher queries as string
         """
-        return self.compile(dsl_content, "cypher")
+        args = []
+        if label_prefix:
+            args.extend(["--label-prefix", label_prefix])
+        if merge:
+            args.append("--cypher-merge")
+        return self.compile(dsl_content, "cypher", extra_args=args)
+    
+    def to_yaml(self, dsl_content: str) -> str:
+        """
+        Compile DSL to a YAML document
+        
+        Args:
+            dsl_content: The personality DSL as string
+            
+        Returns:
+            YAML document as string
+        """
+        return self.compile(dsl_content, "yaml")
+    
+    def to_markdown(self, dsl_content: str) -> str:
+        """
+        Compile DSL to a human-readable Markdown summary
+        
+        Args:
+            dsl_content: The personality DSL as string
+            
+        Returns:
+            Markdown document as string
+        """
+        return self.compile(dsl_content, "markdown")
     
     def load_file(self, filepath: Union[str, Path]) -> str:
         """
@@ -516,6 +562,29 @@ class Callosum:
             return True
         except (ParseError, CompileError):
             return False
+    
+    def get_version(self) -> str:
+        """
+        Get the version of the linked dsl-parser binary
+        
+        Returns:
+            Version string, including the git describe suffix when the
+            binary was built from a git checkout (e.g. "0.2.0+v0.2.0-3-g1a2b3c4")
+        """
+        try:
+            result = subprocess.run(
+                [self.compiler_path, "--version"],
+                capture_output=True,
+                text=True,
+                timeout=10
+            )
+        except (subprocess.SubprocessError, FileNotFoundError) as e:
+            raise CallosumError(f"Failed to query compiler version: {e}")
+        
+        if result.returncode != 0:
+            raise CallosumError(result.stderr.strip() or "Failed to query compiler version")
+        
+        return result.stdout.strip().replace("dsl-parser v", "", 1)
 
 
 class PersonalityAI:
@@ -67,7 +67,7 @@ evolution:
     
     # Example 4: All compilation targets
     print("\n4️⃣ All Compilation Targets")
-    formats = ["json", "prompt", "lua", "sql", "cypher"]
+    formats = ["json", "prompt", "lua", "sql", "cypher", "yaml", "markdown"]
     
     for fmt in formats:
         output = callosum.compile(custom_dsl, fmt)